@@ -18,6 +18,22 @@ supports some additional capabilities:
 * Merge a [`YamlHash`] with another [`YamlHash`], YAML hash string, or YAML hash file to create a
   new [`YamlHash`] via [`merge`][`YamlHash::merge`], [`merge_str`][`YamlHash::merge_str`], or
   [`merge_file`][`YamlHash::merge_file`]
+* Resolve YAML merge keys (`<<`) while loading a hash string or file, so `<<: *anchor` documents
+  expand the same way they do in most other YAML tooling
+* Merge every document of a `---`-separated YAML string via
+  [`merge_all_str`][`YamlHash::merge_all_str`], load each document as its own [`YamlHash`] via
+  [`documents_from_str`][`YamlHash::documents_from_str`], or scope a single document to a
+  top-level namespace via [`from_str_namespaced`][`YamlHash::from_str_namespaced`];
+  [`merge_str`][`YamlHash::merge_str`] itself now errors on multi-document input
+* Set or remove the value for a dotted key via [`set`][`YamlHash::set`] and
+  [`remove`][`YamlHash::remove`] to create a new [`YamlHash`]
+* Choose how conflicting [`Yaml::Array`] values are combined during a merge via [`MergeStrategy`]
+  and the [`merge_with`][`YamlHash::merge_with`], [`merge_str_with`][`YamlHash::merge_str_with`],
+  and [`merge_file_with`][`YamlHash::merge_file_with`] variants
+* Parse and merge errors from [`merge_str`][`YamlHash::merge_str`] and
+  [`merge_file`][`YamlHash::merge_file`] (and their `_with` variants) report the originating file
+  path (or `"<str>"`); a YAML syntax error also reports its line and column, and a rejected merge
+  also reports the dotted key being processed
 
 [`serde`]: https://docs.rs/serde
 [`serde_yaml`]: https://docs.rs/serde_yaml
@@ -45,6 +61,16 @@ Improved YAML Hash
 * Merge a [`YamlHash`] with another [`YamlHash`], YAML hash string, or YAML hash file to create a
   new [`YamlHash`] via [`merge`][`YamlHash::merge`], [`merge_str`][`YamlHash::merge_str`], or
   [`merge_file`][`YamlHash::merge_file`]
+* Resolve YAML merge keys (`<<`) while loading a hash string or file
+* Merge every document of a multi-document YAML string via
+  [`merge_all_str`][`YamlHash::merge_all_str`], load each as its own [`YamlHash`] via
+  [`documents_from_str`][`YamlHash::documents_from_str`], or scope to a namespace via
+  [`from_str_namespaced`][`YamlHash::from_str_namespaced`]
+* Set or remove the value for a dotted key via [`set`][`YamlHash::set`] and
+  [`remove`][`YamlHash::remove`] to create a new [`YamlHash`]
+* Choose how conflicting arrays are combined during a merge via [`MergeStrategy`]
+* Parse and merge errors report the originating file path (or `"<str>"`); a YAML syntax error
+  also reports its line and column, and a rejected merge also reports the dotted key being processed
 
 */
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -52,6 +78,27 @@ pub struct YamlHash {
     data: Hash,
 }
 
+/**
+Strategy for combining [`Yaml::Array`] values found under the same key on both sides of a merge;
+see [`YamlHash::merge_with`]
+
+Hashes always merge recursively and scalars always take the right-hand value regardless of
+strategy; this only changes what happens when both sides hold an array.
+*/
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MergeStrategy {
+    /// Replace the left array with the right array (the default, matching [`YamlHash::merge`])
+    #[default]
+    ReplaceArrays,
+
+    /// Concatenate the left array followed by the right array
+    ConcatArrays,
+
+    /// Concatenate the left array followed by the right array, then remove duplicate elements,
+    /// keeping the first occurrence of each
+    UniqueConcatArrays,
+}
+
 impl YamlHash {
     /// Create a new empty [`YamlHash`]
     #[must_use]
@@ -93,14 +140,43 @@ impl YamlHash {
     */
     #[must_use]
     pub fn merge(&self, other: &YamlHash) -> YamlHash {
+        self.merge_with(other, MergeStrategy::default())
+    }
+
+    /**
+    Merge this [`YamlHash`] with another [`YamlHash`] using a [`MergeStrategy`] to create a new
+    [`YamlHash`]
+
+    ```
+    use yaml_hash::{MergeStrategy, YamlHash};
+
+    let hash = YamlHash::from("fruit:\n  - apple\n  - banana");
+    let other = YamlHash::from("fruit:\n  - banana\n  - cherry");
+
+    assert_eq!(
+        hash.merge_with(&other, MergeStrategy::ConcatArrays).to_string(),
+        "fruit:\n  - apple\n  - banana\n  - banana\n  - cherry",
+    );
+
+    assert_eq!(
+        hash.merge_with(&other, MergeStrategy::UniqueConcatArrays).to_string(),
+        "fruit:\n  - apple\n  - banana\n  - cherry",
+    );
+    ```
+    */
+    #[must_use]
+    pub fn merge_with(&self, other: &YamlHash, strategy: MergeStrategy) -> YamlHash {
         let mut r = self.clone();
-        r.data = merge(&r.data, &other.data);
+        r.data = merge(&r.data, &other.data, strategy);
         r
     }
 
     /**
     Merge this [`YamlHash`] with a YAML hash [`&str`] to create a new [`YamlHash`]
 
+    YAML merge keys (`<<`) in `s` are resolved before merging, so `<<: *anchor` documents expand
+    as expected.
+
     ```
     use yaml_hash::YamlHash;
 
@@ -132,22 +208,170 @@ impl YamlHash {
 
     # Errors
 
-    Returns an error if the YAML string is not a hash
+    Returns an error if the YAML string is not a hash, contains an invalid merge key value,
+    contains more than one document (use [`merge_all_str`][`YamlHash::merge_all_str`] or
+    [`documents_from_str`][`YamlHash::documents_from_str`] for multi-document input), or would
+    overwrite an existing hash with a non-hash value; a YAML syntax error includes `"<str>"` and
+    the line and column where parsing failed, and a rejected merge includes `"<str>"` and the
+    dotted key being processed
     */
     pub fn merge_str(&self, s: &str) -> Result<YamlHash> {
+        self.merge_str_with(s, MergeStrategy::default())
+    }
+
+    /**
+    Merge this [`YamlHash`] with a YAML hash [`&str`] using a [`MergeStrategy`] to create a new
+    [`YamlHash`]
+
+    ```
+    use yaml_hash::{MergeStrategy, YamlHash};
+
+    let hash = YamlHash::from("fruit:\n  - apple");
+
+    let hash = hash
+        .merge_str_with("fruit:\n  - apple\n  - banana", MergeStrategy::UniqueConcatArrays)
+        .unwrap();
+
+    assert_eq!(hash.to_string(), "fruit:\n  - apple\n  - banana");
+    ```
+
+    # Errors
+
+    Returns an error if the YAML string is not a hash, contains an invalid merge key value,
+    contains more than one document (use [`merge_all_str`][`YamlHash::merge_all_str`] or
+    [`documents_from_str`][`YamlHash::documents_from_str`] for multi-document input), or would
+    overwrite an existing hash with a non-hash value; a YAML syntax error includes `"<str>"` and
+    the line and column where parsing failed, and a rejected merge includes `"<str>"` and the
+    dotted key being processed
+    */
+    pub fn merge_str_with(&self, s: &str, strategy: MergeStrategy) -> Result<YamlHash> {
+        self.merge_str_ctx(s, strategy, "<str>")
+    }
+
+    /// Shared implementation of `merge_str_with`/`merge_file_with`; `ctx` (a file path or
+    /// `"<str>"`) is included in any error so the offending source can be found
+    fn merge_str_ctx(&self, s: &str, strategy: MergeStrategy, ctx: &str) -> Result<YamlHash> {
+        let docs = load_hash_docs(s, ctx)?;
+
+        if docs.len() > 1 {
+            return Err(anyhow!(
+                "{ctx}: expected a single YAML document, found {}",
+                docs.len()
+            ));
+        }
+
         let mut r = self.clone();
 
-        for doc in YamlLoader::load_from_str(s)? {
-            if let Yaml::Hash(h) = doc {
-                r.data = merge(&r.data, &h);
-            } else {
-                return Err(anyhow!("YAML string is not a hash: {doc:?}"));
-            }
+        for h in docs {
+            check_mergeable(&r.data, &h, ctx, "")?;
+            r.data = merge(&r.data, &h, strategy);
+        }
+
+        Ok(r)
+    }
+
+    /**
+    Merge this [`YamlHash`] with every document in a multi-document YAML [`&str`] to create a new
+    [`YamlHash`]
+
+    Unlike [`merge_str`][`YamlHash::merge_str`], this folds every document in `s` into the result
+    in order, so keys from later documents can collide with and overwrite keys from earlier ones.
+
+    ```
+    use yaml_hash::YamlHash;
+
+    let hash = YamlHash::new();
+
+    let hash = hash.merge_all_str("\
+    fruit:
+      apple: 1
+    ---
+    fruit:
+      banana: 2\
+    ").unwrap();
+
+    assert_eq!(hash.to_string(), "fruit:\n  apple: 1\n  banana: 2");
+    ```
+
+    # Errors
+
+    Returns an error if any document in the YAML string is not a hash, contains an invalid merge
+    key value, or would overwrite an existing hash with a non-hash value; a YAML syntax error
+    includes `"<str>"` and the line and column where parsing failed, and a rejected merge includes
+    `"<str>"` and the dotted key being processed
+    */
+    pub fn merge_all_str(&self, s: &str) -> Result<YamlHash> {
+        let mut r = self.clone();
+
+        for h in load_hash_docs(s, "<str>")? {
+            check_mergeable(&r.data, &h, "<str>", "")?;
+            r.data = merge(&r.data, &h, MergeStrategy::default());
         }
 
         Ok(r)
     }
 
+    /**
+    Load every document in a multi-document YAML [`&str`] as its own [`YamlHash`]
+
+    ```
+    use yaml_hash::YamlHash;
+
+    let docs = YamlHash::documents_from_str("\
+    fruit:
+      apple: 1
+    ---
+    fruit:
+      banana: 2\
+    ").unwrap();
+
+    assert_eq!(docs.len(), 2);
+    assert_eq!(docs[0].to_string(), "fruit:\n  apple: 1");
+    assert_eq!(docs[1].to_string(), "fruit:\n  banana: 2");
+    ```
+
+    # Errors
+
+    Returns an error if any document in the YAML string is not a hash or contains an invalid merge
+    key value
+    */
+    pub fn documents_from_str(s: &str) -> Result<Vec<YamlHash>> {
+        Ok(load_hash_docs(s, "<str>")?
+            .into_iter()
+            .map(|data| YamlHash { data })
+            .collect())
+    }
+
+    /**
+    Load a single-document YAML [`&str`] and return the sub-tree rooted at a top-level `namespace`
+    key as a new [`YamlHash`]
+
+    ```
+    use yaml_hash::YamlHash;
+
+    let hash = YamlHash::from_str_namespaced("\
+    app:
+      fruit:
+        apple: 1\
+    ", "app").unwrap();
+
+    assert_eq!(hash.to_string(), "fruit:\n  apple: 1");
+    ```
+
+    # Errors
+
+    Returns an error if the YAML string is not a single hash document, or `namespace` is not a key
+    of the root hash whose value is itself a hash
+    */
+    pub fn from_str_namespaced(s: &str, namespace: &str) -> Result<YamlHash> {
+        let root = YamlHash::new().merge_str(s)?;
+        match root.data.get(&Yaml::String(namespace.to_string())) {
+            Some(Yaml::Hash(data)) => Ok(YamlHash { data: data.clone() }),
+            Some(_) => Err(anyhow!("Value for {namespace:?} is not a hash")),
+            None => Err(anyhow!("Invalid key: {namespace:?}")),
+        }
+    }
+
     /**
     Merge this [`YamlHash`] with a YAML hash file to create a new [`YamlHash`]
 
@@ -175,16 +399,55 @@ impl YamlHash {
 
     # Errors
 
-    Returns an error if not able to read the file at the given path to a string
+    Returns an error if not able to read the file at the given path to a string, the YAML in it is
+    not a hash, or merging it would overwrite an existing hash with a non-hash value; a YAML
+    syntax error includes the file path and the line and column where parsing failed, and a
+    rejected merge includes the file path and the dotted key being processed
     */
     pub fn merge_file<P: AsRef<Path>>(&self, path: P) -> Result<YamlHash> {
-        let yaml = std::fs::read_to_string(path)?;
-        self.merge_str(&yaml)
+        self.merge_file_with(path, MergeStrategy::default())
+    }
+
+    /**
+    Merge this [`YamlHash`] with a YAML hash file using a [`MergeStrategy`] to create a new
+    [`YamlHash`]
+
+    ```
+    use yaml_hash::{MergeStrategy, YamlHash};
+
+    let hash = YamlHash::from("fruit:\n  apple: 1\n  banana: 2");
+
+    let hash = hash
+        .merge_file_with("tests/b.yaml", MergeStrategy::ConcatArrays)
+        .unwrap();
+
+    assert_eq!(hash.to_string(), "fruit:\n  apple: 1\n  banana: 2\n  cherry: 3");
+    ```
+
+    # Errors
+
+    Returns an error if not able to read the file at the given path to a string, the YAML in it is
+    not a hash, or merging it would overwrite an existing hash with a non-hash value; a YAML
+    syntax error includes the file path and the line and column where parsing failed, and a
+    rejected merge includes the file path and the dotted key being processed
+    */
+    pub fn merge_file_with<P: AsRef<Path>>(
+        &self,
+        path: P,
+        strategy: MergeStrategy,
+    ) -> Result<YamlHash> {
+        let path = path.as_ref();
+        let yaml =
+            std::fs::read_to_string(path).map_err(|e| anyhow!("{}: {e}", path.display()))?;
+        self.merge_str_ctx(&yaml, strategy, &path.display().to_string())
     }
 
     /**
     Get the value for a dotted key as a [`Yaml`]
 
+    A path segment that lands on a [`Yaml::Array`] is parsed as an index into it, so sequences of
+    mappings can be descended into just like nested hashes.
+
     ```
     use yaml_hash::{Yaml, YamlHash};
 
@@ -194,18 +457,27 @@ impl YamlHash {
       banana: 2
       cherry:
         sweet: 1
-        tart: 2\
+        tart: 2
+    servers:
+      - host: a
+      - host: b\
     ");
 
     assert_eq!(
         hash.get_yaml("fruit.cherry.tart").unwrap(),
         Yaml::Integer(2),
     );
+
+    assert_eq!(
+        hash.get_yaml("servers.1.host").unwrap(),
+        Yaml::String("b".to_string()),
+    );
     ```
 
     # Errors
 
-    Returns an error if the given key is not valid or the value is not a hash
+    Returns an error if the given key is not valid, an array index is out of bounds or not a
+    number, or the value is not a hash or an array
     */
     pub fn get_yaml(&self, key: &str) -> Result<Yaml> {
         get_yaml(key, ".", &Yaml::Hash(self.data.clone()), "")
@@ -245,6 +517,67 @@ impl YamlHash {
             None => Err(anyhow!("Value for {key:?} is not a hash")),
         }
     }
+
+    /**
+    Set the value for a dotted key to create a new [`YamlHash`]
+
+    Intermediate hashes that don't yet exist are created automatically, the same way
+    [`merge`][`YamlHash::merge`] creates them for a nested document.
+
+    ```
+    use yaml_hash::{Yaml, YamlHash};
+
+    let hash = YamlHash::from("\
+    fruit:
+      apple: 1\
+    ");
+
+    let hash = hash.set("fruit.cherry.tart", Yaml::Integer(2));
+
+    assert_eq!(
+        hash.to_string(),
+        "\
+    fruit:
+      apple: 1
+      cherry:
+        tart: 2\
+        ",
+    );
+    ```
+    */
+    #[must_use]
+    pub fn set(&self, key: &str, value: Yaml) -> YamlHash {
+        let mut r = self.clone();
+        r.data = set(&r.data, key, ".", value);
+        r
+    }
+
+    /**
+    Remove the value for a dotted key to create a new [`YamlHash`]
+
+    ```
+    use yaml_hash::YamlHash;
+
+    let hash = YamlHash::from("\
+    fruit:
+      apple: 1
+      banana: 2\
+    ");
+
+    let hash = hash.remove("fruit.banana").unwrap();
+
+    assert_eq!(hash.to_string(), "fruit:\n  apple: 1");
+    ```
+
+    # Errors
+
+    Returns an error if the given key is not valid
+    */
+    pub fn remove(&self, key: &str) -> Result<YamlHash> {
+        let mut r = self.clone();
+        r.data = remove(&r.data, key, ".", "")?;
+        Ok(r)
+    }
 }
 
 impl std::fmt::Display for YamlHash {
@@ -258,25 +591,194 @@ impl std::fmt::Display for YamlHash {
 }
 
 impl From<&str> for YamlHash {
-    /// Create a [`YamlHash`] from a YAML hash string
+    /// Create a [`YamlHash`] from a YAML hash string, merging multiple documents in order if
+    /// present (see [`merge_all_str`][`YamlHash::merge_all_str`])
     fn from(s: &str) -> YamlHash {
-        YamlHash::default().merge_str(s).unwrap()
+        YamlHash::default().merge_all_str(s).unwrap()
     }
 }
 
 //--------------------------------------------------------------------------------------------------
 
-fn merge(a: &Hash, b: &Hash) -> Hash {
+/// Parse `s` into its YAML hash documents, resolving merge keys (`<<`) in each one; `ctx` (a file
+/// path or `"<str>"`) and the scan error's line and column are included in any parse error so the
+/// offending source can be found
+fn load_hash_docs(s: &str, ctx: &str) -> Result<Vec<Hash>> {
+    let mut r = Vec::new();
+
+    let docs = YamlLoader::load_from_str(s).map_err(|e| {
+        let marker = e.marker();
+        anyhow!("{ctx}:{}:{}: {}", marker.line(), marker.col(), e.info())
+    })?;
+
+    for doc in docs {
+        if let Yaml::Hash(h) = doc {
+            match resolve_merge_keys(&Yaml::Hash(h), ctx, "")? {
+                Yaml::Hash(h) => r.push(h),
+                _ => unreachable!(),
+            }
+        } else {
+            return Err(anyhow!("{ctx}: YAML string is not a hash: {doc:?}"));
+        }
+    }
+
+    Ok(r)
+}
+
+/**
+Recursively resolve YAML merge keys (`<<`) in `yaml`
+
+A hash containing the key `<<` has its value merged in: the value must be a single hash or an
+array of hashes (anything else is an error). Explicit keys in the hash always win; within an
+array of merge sources, earlier hashes win over later ones. The `<<` key is removed from the
+result.
+
+`ctx` (a file path or `"<str>"`) and `path` (the dotted key processed so far) are included in any
+error.
+*/
+fn resolve_merge_keys(yaml: &Yaml, ctx: &str, path: &str) -> Result<Yaml> {
+    match yaml {
+        Yaml::Hash(hash) => {
+            let merge_key = Yaml::String("<<".to_string());
+            let mut r = Hash::new();
+            let mut sources = Vec::new();
+
+            for (k, v) in hash {
+                if *k == merge_key {
+                    match resolve_merge_keys(v, ctx, path)? {
+                        Yaml::Hash(h) => sources.push(h),
+                        Yaml::Array(a) => {
+                            for item in a {
+                                if let Yaml::Hash(h) = item {
+                                    sources.push(h);
+                                } else {
+                                    return Err(anyhow!(
+                                        "{ctx}: key {path:?} has invalid merge value, found {}",
+                                        yaml_type_name(&item)
+                                    ));
+                                }
+                            }
+                        }
+                        other => {
+                            return Err(anyhow!(
+                                "{ctx}: key {path:?} has invalid merge value, found {}",
+                                yaml_type_name(&other)
+                            ));
+                        }
+                    }
+                } else {
+                    let key_path = if path.is_empty() {
+                        key_str(k)
+                    } else {
+                        format!("{path}.{}", key_str(k))
+                    };
+                    r.insert(k.clone(), resolve_merge_keys(v, ctx, &key_path)?);
+                }
+            }
+
+            for source in sources {
+                for (k, v) in source {
+                    if !r.contains_key(&k) {
+                        r.insert(k, v);
+                    }
+                }
+            }
+
+            Ok(Yaml::Hash(r))
+        }
+        Yaml::Array(a) => Ok(Yaml::Array(
+            a.iter()
+                .map(|item| resolve_merge_keys(item, ctx, path))
+                .collect::<Result<_>>()?,
+        )),
+        _ => Ok(yaml.clone()),
+    }
+}
+
+/// The key of a [`Yaml::String`], or its [`Debug`][`std::fmt::Debug`] form for any other variant
+fn key_str(key: &Yaml) -> String {
+    match key {
+        Yaml::String(s) => s.clone(),
+        other => format!("{other:?}"),
+    }
+}
+
+/// The name of a [`Yaml`] variant, for use in error messages
+fn yaml_type_name(yaml: &Yaml) -> &'static str {
+    match yaml {
+        Yaml::Real(_) => "Real",
+        Yaml::Integer(_) => "Integer",
+        Yaml::String(_) => "String",
+        Yaml::Boolean(_) => "Boolean",
+        Yaml::Array(_) => "Array",
+        Yaml::Hash(_) => "Hash",
+        Yaml::Alias(_) => "Alias",
+        Yaml::Null => "Null",
+        Yaml::BadValue => "BadValue",
+    }
+}
+
+/**
+Check that merging `b` into `a` would not overwrite a hash with a non-hash value; `ctx` and `path`
+are included in any error the same way as [`resolve_merge_keys`]
+
+[`merge`] itself silently lets a later document's scalar replace an earlier hash at the same key,
+which usually indicates a mistake when layering config fragments, so entry points that have a
+file or string context (like [`YamlHash::merge_str_with`]) check for it up front instead.
+*/
+fn check_mergeable(a: &Hash, b: &Hash, ctx: &str, path: &str) -> Result<()> {
+    for (k, v) in b {
+        if let Some(Yaml::Hash(ah)) = a.get(k) {
+            let key_path = if path.is_empty() {
+                key_str(k)
+            } else {
+                format!("{path}.{}", key_str(k))
+            };
+            match v {
+                Yaml::Hash(bh) => check_mergeable(ah, bh, ctx, &key_path)?,
+                other => {
+                    return Err(anyhow!(
+                        "{ctx}: key {key_path:?} expected a hash, found {}",
+                        yaml_type_name(other)
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn merge(a: &Hash, b: &Hash, strategy: MergeStrategy) -> Hash {
     let mut r = a.clone();
     for (k, v) in b {
         if let Yaml::Hash(bh) = v
             && let Some(Yaml::Hash(rh)) = r.get(k)
         {
+            let merged = Yaml::Hash(merge(rh, bh, strategy));
             if r.contains_key(k) {
-                r.replace(k.clone(), Yaml::Hash(merge(rh, bh)));
+                r.replace(k.clone(), merged);
             } else {
-                r.insert(k.clone(), Yaml::Hash(merge(rh, bh)));
+                r.insert(k.clone(), merged);
+            }
+            continue;
+        }
+        if !matches!(strategy, MergeStrategy::ReplaceArrays)
+            && let Yaml::Array(bv) = v
+            && let Some(Yaml::Array(rv)) = r.get(k)
+        {
+            let mut combined = rv.clone();
+            combined.extend(bv.clone());
+            if matches!(strategy, MergeStrategy::UniqueConcatArrays) {
+                let mut unique = Vec::with_capacity(combined.len());
+                for item in combined {
+                    if !unique.contains(&item) {
+                        unique.push(item);
+                    }
+                }
+                combined = unique;
             }
+            r.replace(k.clone(), Yaml::Array(combined));
             continue;
         }
         if r.contains_key(k) {
@@ -313,6 +815,86 @@ fn get_yaml(key: &str, sep: &str, yaml: &Yaml, full: &str) -> Result<Yaml> {
             }
             None => Err(anyhow!("Invalid key: {full:?}")),
         },
+        Yaml::Array(array) => {
+            let index: usize = this
+                .parse()
+                .map_err(|_| anyhow!("Expected index, found {this:?}"))?;
+            match array.get(index) {
+                Some(v) => {
+                    if next.is_empty() {
+                        Ok(v.clone())
+                    } else {
+                        let full = if full.is_empty() {
+                            key.to_string()
+                        } else {
+                            format!("{full}.{this}")
+                        };
+                        get_yaml(&next, sep, v, &full)
+                    }
+                }
+                None => Err(anyhow!("Index {index} out of bounds for key {full:?}")),
+            }
+        }
         _ => Err(anyhow!("Value for key {full:?} is not a hash")),
     }
 }
+
+/// Set the value for a dotted key in `hash`, creating intermediate hashes as needed
+fn set(hash: &Hash, key: &str, sep: &str, value: Yaml) -> Hash {
+    let mut r = hash.clone();
+
+    let mut s = key.split(sep);
+    let this = s.next().unwrap();
+    let next = s.collect::<Vec<&str>>().join(sep);
+    let k = Yaml::String(this.to_string());
+
+    let v = if next.is_empty() {
+        value
+    } else {
+        let child = match r.get(&k) {
+            Some(Yaml::Hash(child)) => child.clone(),
+            _ => Hash::new(),
+        };
+        Yaml::Hash(set(&child, &next, sep, value))
+    };
+
+    if r.contains_key(&k) {
+        r.replace(k, v);
+    } else {
+        r.insert(k, v);
+    }
+
+    r
+}
+
+/// Remove the value for a dotted key from `hash`
+fn remove(hash: &Hash, key: &str, sep: &str, full: &str) -> Result<Hash> {
+    let mut r = hash.clone();
+
+    let mut s = key.split(sep);
+    let this = s.next().unwrap();
+    let next = s.collect::<Vec<&str>>().join(sep);
+    let k = Yaml::String(this.to_string());
+
+    let full = if full.is_empty() {
+        key.to_string()
+    } else {
+        format!("{full}.{this}")
+    };
+
+    if next.is_empty() {
+        if r.remove(&k).is_none() {
+            return Err(anyhow!("Invalid key: {full:?}"));
+        }
+    } else {
+        match r.get(&k) {
+            Some(Yaml::Hash(child)) => {
+                let child = remove(child, &next, sep, &full)?;
+                r.replace(k, Yaml::Hash(child));
+            }
+            _ => return Err(anyhow!("Invalid key: {full:?}")),
+        }
+    }
+
+    Ok(r)
+}
@@ -226,6 +226,74 @@ fn merge_multiple_file_file_with_conflict() {
 
 //--------------------------------------------------------------------------------------------------
 
+#[test]
+fn merge_key() {
+    let hash = YamlHash::new();
+
+    let yaml = "\
+base: &base
+  apple: 1
+  banana: 2
+fruit:
+  <<: *base
+  cherry: 3";
+
+    let result = "base:\n  apple: 1\n  banana: 2\nfruit:\n  cherry: 3\n  apple: 1\n  banana: 2";
+
+    let hash = hash.merge_str(yaml).unwrap();
+
+    assert_eq!(hash.to_string(), result);
+}
+
+#[test]
+fn merge_key_explicit_key_wins() {
+    let hash = YamlHash::new();
+
+    let yaml = "\
+base: &base
+  apple: 1
+fruit:
+  <<: *base
+  apple: 2";
+
+    let result = "base:\n  apple: 1\nfruit:\n  apple: 2";
+
+    let hash = hash.merge_str(yaml).unwrap();
+
+    assert_eq!(hash.to_string(), result);
+}
+
+#[test]
+fn merge_key_array_first_wins() {
+    let hash = YamlHash::new();
+
+    let yaml = "\
+a: &a
+  apple: 1
+b: &b
+  apple: 2
+  banana: 2
+fruit:
+  <<: [*a, *b]";
+
+    let result = "a:\n  apple: 1\nb:\n  apple: 2\n  banana: 2\nfruit:\n  apple: 1\n  banana: 2";
+
+    let hash = hash.merge_str(yaml).unwrap();
+
+    assert_eq!(hash.to_string(), result);
+}
+
+#[test]
+fn merge_key_invalid_value() {
+    let hash = YamlHash::new();
+
+    let yaml = "fruit:\n  <<: 1\n  apple: 2";
+
+    assert!(hash.merge_str(yaml).is_err());
+}
+
+//--------------------------------------------------------------------------------------------------
+
 #[test]
 fn get() {
     let hash = YamlHash::new();
@@ -255,3 +323,243 @@ fn get() {
     let sweet2 = hash.get_yaml("fruit.cherry.sweet").unwrap();
     assert_eq!(sweet2, Yaml::Integer(3));
 }
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn get_yaml_array() {
+    let hash = YamlHash::new();
+
+    let hash = hash
+        .merge_str("servers:\n  - host: a\n    ports:\n      - 80\n      - 443\n  - host: b")
+        .unwrap();
+
+    assert_eq!(
+        hash.get_yaml("servers.0.host").unwrap(),
+        Yaml::String("a".to_string()),
+    );
+    assert_eq!(
+        hash.get_yaml("servers.1.host").unwrap(),
+        Yaml::String("b".to_string()),
+    );
+    assert_eq!(
+        hash.get_yaml("servers.0.ports.1").unwrap(),
+        Yaml::Integer(443),
+    );
+}
+
+#[test]
+fn get_yaml_array_index_out_of_bounds() {
+    let hash = YamlHash::new();
+
+    let hash = hash.merge_str("servers:\n  - host: a").unwrap();
+
+    assert!(hash.get_yaml("servers.5").is_err());
+}
+
+#[test]
+fn get_yaml_array_non_numeric_index() {
+    let hash = YamlHash::new();
+
+    let hash = hash.merge_str("servers:\n  - host: a").unwrap();
+
+    assert!(hash.get_yaml("servers.host").is_err());
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn set_existing_path() {
+    let hash = YamlHash::new();
+
+    let hash = hash
+        .merge_str("fruit:\n  apple: 1\n  banana: 2")
+        .unwrap()
+        .set("fruit.banana", Yaml::Integer(3));
+
+    assert_eq!(hash.to_string(), "fruit:\n  apple: 1\n  banana: 3");
+}
+
+#[test]
+fn set_creates_intermediate_hashes() {
+    let hash = YamlHash::new();
+
+    let hash = hash
+        .merge_str("fruit:\n  apple: 1")
+        .unwrap()
+        .set("fruit.cherry.tart", Yaml::Integer(2));
+
+    assert_eq!(
+        hash.to_string(),
+        "fruit:\n  apple: 1\n  cherry:\n    tart: 2"
+    );
+}
+
+#[test]
+fn remove_existing_key() {
+    let hash = YamlHash::new();
+
+    let hash = hash
+        .merge_str("fruit:\n  apple: 1\n  banana: 2")
+        .unwrap()
+        .remove("fruit.banana")
+        .unwrap();
+
+    assert_eq!(hash.to_string(), "fruit:\n  apple: 1");
+}
+
+#[test]
+fn remove_invalid_key() {
+    let hash = YamlHash::new();
+
+    let hash = hash.merge_str("fruit:\n  apple: 1").unwrap();
+
+    assert!(hash.remove("fruit.cherry").is_err());
+    assert!(hash.remove("vegetable.carrot").is_err());
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn merge_str_rejects_multiple_documents() {
+    let hash = YamlHash::new();
+
+    let yaml = "fruit:\n  apple: 1\n---\nfruit:\n  banana: 2";
+
+    assert!(hash.merge_str(yaml).is_err());
+}
+
+#[test]
+fn merge_all_str() {
+    let hash = YamlHash::new();
+
+    let yaml = "fruit:\n  apple: 1\n---\nfruit:\n  banana: 2";
+
+    let hash = hash.merge_all_str(yaml).unwrap();
+
+    assert_eq!(hash.to_string(), "fruit:\n  apple: 1\n  banana: 2");
+}
+
+#[test]
+fn documents_from_str() {
+    let yaml = "fruit:\n  apple: 1\n---\nfruit:\n  banana: 2";
+
+    let docs = YamlHash::documents_from_str(yaml).unwrap();
+
+    assert_eq!(docs.len(), 2);
+    assert_eq!(docs[0].to_string(), "fruit:\n  apple: 1");
+    assert_eq!(docs[1].to_string(), "fruit:\n  banana: 2");
+}
+
+#[test]
+fn from_str_namespaced() {
+    let yaml = "app:\n  fruit:\n    apple: 1\nother:\n  x: 1";
+
+    let hash = YamlHash::from_str_namespaced(yaml, "app").unwrap();
+
+    assert_eq!(hash.to_string(), "fruit:\n  apple: 1");
+}
+
+#[test]
+fn from_str_namespaced_missing_namespace() {
+    let yaml = "app:\n  fruit:\n    apple: 1";
+
+    assert!(YamlHash::from_str_namespaced(yaml, "missing").is_err());
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn merge_replace_arrays_default() {
+    let hash = YamlHash::from("fruit:\n  - apple\n  - banana");
+    let other = YamlHash::from("fruit:\n  - cherry");
+
+    assert_eq!(hash.merge(&other).to_string(), "fruit:\n  - cherry");
+}
+
+#[test]
+fn merge_with_concat_arrays() {
+    let hash = YamlHash::from("fruit:\n  - apple\n  - banana");
+    let other = YamlHash::from("fruit:\n  - banana\n  - cherry");
+
+    let hash = hash.merge_with(&other, MergeStrategy::ConcatArrays);
+
+    assert_eq!(
+        hash.to_string(),
+        "fruit:\n  - apple\n  - banana\n  - banana\n  - cherry"
+    );
+}
+
+#[test]
+fn merge_with_unique_concat_arrays() {
+    let hash = YamlHash::from("fruit:\n  - apple\n  - banana");
+    let other = YamlHash::from("fruit:\n  - banana\n  - cherry");
+
+    let hash = hash.merge_with(&other, MergeStrategy::UniqueConcatArrays);
+
+    assert_eq!(hash.to_string(), "fruit:\n  - apple\n  - banana\n  - cherry");
+}
+
+#[test]
+fn merge_str_with_concat_arrays() {
+    let hash = YamlHash::from("fruit:\n  - apple");
+
+    let hash = hash
+        .merge_str_with("fruit:\n  - banana", MergeStrategy::ConcatArrays)
+        .unwrap();
+
+    assert_eq!(hash.to_string(), "fruit:\n  - apple\n  - banana");
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn merge_str_parse_error_includes_line_and_column() {
+    let hash = YamlHash::new();
+
+    let err = hash.merge_str("fruit: [").unwrap_err().to_string();
+
+    let rest = err.strip_prefix("<str>:").unwrap_or_else(|| panic!("{err}"));
+    let mut parts = rest.splitn(3, ':');
+    parts
+        .next()
+        .unwrap()
+        .parse::<usize>()
+        .unwrap_or_else(|_| panic!("{err}"));
+    parts
+        .next()
+        .unwrap()
+        .parse::<usize>()
+        .unwrap_or_else(|_| panic!("{err}"));
+}
+
+#[test]
+fn merge_str_not_a_hash_includes_context() {
+    let hash = YamlHash::new();
+
+    let err = hash.merge_str("- apple\n- banana").unwrap_err().to_string();
+
+    assert!(err.starts_with("<str>: "), "{err}");
+}
+
+#[test]
+fn merge_str_rejects_hash_overwritten_by_scalar() {
+    let hash = YamlHash::from("fruit:\n  cherry:\n    sweet: 1");
+
+    let err = hash.merge_str("fruit:\n  cherry: 2").unwrap_err().to_string();
+
+    assert!(err.contains("fruit.cherry"), "{err}");
+    assert!(err.contains("Integer"), "{err}");
+}
+
+#[test]
+fn merge_file_error_includes_path() {
+    let hash = YamlHash::new();
+
+    let err = hash
+        .merge_file("tests/does-not-exist.yaml")
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.starts_with("tests/does-not-exist.yaml: "), "{err}");
+}